@@ -1,42 +1,215 @@
 use std::num::TryFromIntError;
+use std::ops::RangeInclusive;
 
-use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
 
 use crate::{Error, Result};
 
+/// Préréglage régional des jours fériés.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Region {
+    /// Jours fériés nationaux français.
+    France,
+    /// France + Vendredi saint (Pâques - 2) et Saint-Étienne (26 décembre).
+    AlsaceMoselle,
+}
+
+impl Region {
+    /// Jours fériés fixes (mois, jour) et décalages relatifs à Pâques du préréglage.
+    fn holidays(self) -> (Vec<(u32, u32)>, Vec<i64>) {
+        let mut fixed = vec![
+            (1, 1),
+            (5, 1),
+            (5, 8),
+            (7, 14),
+            (8, 15),
+            (11, 1),
+            (11, 11),
+            (12, 25),
+        ];
+        let mut easter_offsets = vec![
+            1,  //lundi de Pâques
+            39, //jeudi Ascension
+            50, //Pentecôte
+        ];
+
+        if self == Region::AlsaceMoselle {
+            fixed.push((12, 26)); //Saint-Étienne
+            easter_offsets.push(-2); //Vendredi saint
+        }
+
+        (fixed, easter_offsets)
+    }
+}
+
+/// Heures d'ouverture par jour de semaine, indexées par
+/// `Weekday::num_days_from_monday`. Chaque entrée est la liste ordonnée des
+/// intervalles ouverts du jour, exprimés en durée depuis minuit.
+type OpeningHours = [Vec<(Duration, Duration)>; 7];
+
 pub struct Calendar {
     is_saturday_off: bool,
     is_sunday_off: bool,
     holidays: Vec<NaiveDate>,
+    opening_hours: OpeningHours,
+    week_start: Weekday,
 }
 
-impl Calendar {
-    pub fn new(year: i32) -> Result<Self> {
-        let holidays = build_holidays(year)?;
+/// Profil par défaut : ouvert 24h sur les jours ouvrés.
+fn full_day_opening_hours() -> OpeningHours {
+    let full_day = vec![(Duration::zero(), Duration::hours(24))];
+    std::array::from_fn(|_| full_day.clone())
+}
+
+/// Constructeur composable d'un [`Calendar`] : jours fériés fixes, fériés
+/// relatifs à Pâques, préréglages régionaux et jours de week-end travaillés.
+pub struct CalendarBuilder {
+    years: RangeInclusive<i32>,
+    is_saturday_off: bool,
+    is_sunday_off: bool,
+    fixed_days: Vec<(u32, u32)>,
+    easter_offsets: Vec<i64>,
+    extra_holidays: Vec<NaiveDate>,
+    removed_holidays: Vec<NaiveDate>,
+    opening_hours: OpeningHours,
+    week_start: Weekday,
+}
+
+impl CalendarBuilder {
+    /// Sélectionne un préréglage régional, remplaçant l'ensemble des fériés
+    /// récurrents par celui de la région.
+    pub fn with_preset(mut self, region: Region) -> Self {
+        let (fixed, easter_offsets) = region.holidays();
+        self.fixed_days = fixed;
+        self.easter_offsets = easter_offsets;
+        self
+    }
+
+    /// Ajoute un jour férié fixe arbitraire.
+    pub fn with_holiday(mut self, date: NaiveDate) -> Self {
+        self.extra_holidays.push(date);
+        self
+    }
+
+    /// Retire une date de l'ensemble des jours fériés calculés.
+    pub fn without_holiday(mut self, date: NaiveDate) -> Self {
+        self.removed_holidays.push(date);
+        self
+    }
+
+    /// Enregistre un jour férié relatif à Pâques, exprimé en décalage de jours.
+    pub fn with_easter_holiday(mut self, offset_days: i64) -> Self {
+        self.easter_offsets.push(offset_days);
+        self
+    }
+
+    /// Déclare qu'un jour de week-end est travaillé.
+    pub fn without_weekend_day(mut self, weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Sat => self.is_saturday_off = false,
+            Weekday::Sun => self.is_sunday_off = false,
+            _ => {}
+        }
+        self
+    }
+
+    /// Définit les plages d'ouverture d'un jour de semaine, remplaçant le
+    /// profil « 24h » par défaut pour ce jour.
+    pub fn with_opening_hours(
+        mut self,
+        weekday: Weekday,
+        intervals: Vec<(NaiveTime, NaiveTime)>,
+    ) -> Self {
+        let midnight = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+        let index = weekday.num_days_from_monday() as usize;
+
+        self.opening_hours[index] = intervals
+            .into_iter()
+            .map(|(start, end)| {
+                (
+                    start.signed_duration_since(midnight),
+                    end.signed_duration_since(midnight),
+                )
+            })
+            .collect();
+        self
+    }
+
+    /// Définit le jour de début de semaine (lundi par défaut).
+    pub fn with_week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    pub fn build(self) -> Result<Calendar> {
+        let mut holidays: Vec<NaiveDate> = vec![];
+
+        for year in self.years {
+            let easter = get_easter(year)?;
+
+            for &(month, day) in &self.fixed_days {
+                holidays.push(get_date(year, month, day)?);
+            }
+            for &offset in &self.easter_offsets {
+                holidays.push(easter + Duration::days(offset));
+            }
+        }
+
+        holidays.extend(self.extra_holidays);
+        holidays.retain(|date| !self.removed_holidays.contains(date));
 
         Ok(Calendar {
-            is_saturday_off: true,
-            is_sunday_off: true,
+            is_saturday_off: self.is_saturday_off,
+            is_sunday_off: self.is_sunday_off,
             holidays,
+            opening_hours: self.opening_hours,
+            week_start: self.week_start,
         })
     }
+}
+
+impl Calendar {
+    /// Démarre la construction d'un calendrier couvrant `years`, préchargé avec
+    /// les jours fériés nationaux français et les deux jours de week-end chômés.
+    pub fn builder(years: RangeInclusive<i32>) -> CalendarBuilder {
+        let (fixed_days, easter_offsets) = Region::France.holidays();
+
+        CalendarBuilder {
+            years,
+            is_saturday_off: true,
+            is_sunday_off: true,
+            fixed_days,
+            easter_offsets,
+            extra_holidays: vec![],
+            removed_holidays: vec![],
+            opening_hours: full_day_opening_hours(),
+            week_start: Weekday::Mon,
+        }
+    }
+
+    pub fn new(year: i32) -> Result<Self> {
+        Calendar::builder((year - 1)..=(year + 1)).build()
+    }
 
     pub fn new_with_days_off(
         year: i32,
         is_saturday_off: bool,
         is_sunday_off: bool,
     ) -> Result<Self> {
-        let holidays = build_holidays(year)?;
+        let mut builder = Calendar::builder((year - 1)..=(year + 1));
 
-        Ok(Calendar {
-            is_saturday_off,
-            is_sunday_off,
-            holidays,
-        })
+        if !is_saturday_off {
+            builder = builder.without_weekend_day(Weekday::Sat);
+        }
+        if !is_sunday_off {
+            builder = builder.without_weekend_day(Weekday::Sun);
+        }
+
+        builder.build()
     }
 
     pub fn is_day_off(&self, date: NaiveDate) -> bool {
-        let is_holidays = self.holidays.iter().any(|&x| date == x);
+        let is_holidays = self.holidays.contains(&date);
 
         let saturday_off = (date.weekday() == Weekday::Sat) && self.is_saturday_off;
         let sunday_off = date.weekday() == Weekday::Sun && self.is_sunday_off;
@@ -44,14 +217,78 @@ impl Calendar {
         is_holidays || saturday_off || sunday_off
     }
 
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date)
+    }
+
+    /// Itère sur les jours ouvrés à partir de `start` (inclus), en sautant les
+    /// jours chômés. L'itérateur est infini : utiliser `take(n)` pour obtenir
+    /// « les N prochains jours ouvrés ».
+    pub fn working_days_from(&self, start: NaiveDate) -> WorkingDays<'_> {
+        WorkingDays {
+            calendar: self,
+            next: start,
+        }
+    }
+
+    /// Itère sur les jours fériés contenus dans l'intervalle semi-ouvert
+    /// `[from, to)`, dans l'ordre chronologique.
+    pub fn holidays_between(&self, from: NaiveDate, to: NaiveDate) -> HolidaysBetween<'_> {
+        HolidaysBetween {
+            calendar: self,
+            next: from,
+            end: to,
+        }
+    }
+
     pub fn get_next_working_day(&self, date: NaiveDate, days_added: i32) -> Result<NaiveDate> {
-        self.get_working_day_at_day(date, days_added, add_days)
+        self.working_days_from(add_days(date, days_added))
+            .next()
+            .ok_or_else(|| Error::CalendarError("aucun jour ouvré trouvé".to_string()))
     }
 
     pub fn get_previous_working_day(&self, date: NaiveDate, days_added: i32) -> Result<NaiveDate> {
         self.get_working_day_at_day(date, days_added, minus_days)
     }
 
+    /// Nombre de jours ouvrés dans l'intervalle semi-ouvert `[from, to)`.
+    /// Le résultat est signé : lorsque `from` est postérieur à `to`, le compte
+    /// porte sur `[to, from)` et est renvoyé négatif. Quel que soit le sens, la
+    /// date la plus ancienne est incluse et la plus récente exclue.
+    pub fn working_days_between(&self, from: NaiveDate, to: NaiveDate) -> Result<i64> {
+        let (start, end, sign) = if from <= to { (from, to, 1) } else { (to, from, -1) };
+
+        let count = self.working_days_from(start).take_while(|&d| d < end).count() as i64;
+
+        Ok(count * sign)
+    }
+
+    /// Somme des durées tombant sur des jours ouvrés entre `from` et `to`.
+    /// Suit la même convention que [`Calendar::working_days_between`] : le
+    /// résultat est signé selon le sens de l'intervalle.
+    pub fn net_working_duration(&self, from: NaiveDateTime, to: NaiveDateTime) -> Result<Duration> {
+        let (start, end, sign) = if from <= to { (from, to, 1) } else { (to, from, -1) };
+
+        let mut total = Duration::zero();
+        let mut cursor = start;
+
+        while cursor < end {
+            let next_midnight = NaiveDateTime::new(
+                add_days(cursor.date(), 1),
+                NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+            );
+            let segment_end = next_midnight.min(end);
+
+            if !self.is_day_off(cursor.date()) {
+                total += segment_end - cursor;
+            }
+
+            cursor = segment_end;
+        }
+
+        Ok(total * sign)
+    }
+
     fn get_working_day_at_day(
         &self,
         date: NaiveDate,
@@ -67,29 +304,149 @@ impl Calendar {
         }
     }
 
+    /// Numéro de semaine de `date`, relatif à la locale : il est calculé à
+    /// partir du quantième de l'année et du jour de début de semaine configuré
+    /// (plutôt qu'une hypothèse fixe lundi/dimanche). Ce n'est pas la semaine
+    /// ISO 8601 — les premiers jours de janvier rattachés à l'année précédente
+    /// renvoient `0` ici au lieu de la semaine 52/53.
+    pub fn week_of(&self, date: NaiveDate) -> i64 {
+        let from_start = date.weekday().days_since(self.week_start) as i64;
+
+        (date.ordinal() as i64 - from_start + 7) / 7
+    }
+
+    /// Premier jour ouvré de la semaine contenant `date`.
+    pub fn first_working_day_of_week(&self, date: NaiveDate) -> Result<NaiveDate> {
+        let start = self.start_of_week(date);
+
+        (0..7)
+            .map(|offset| add_days(start, offset))
+            .find(|&day| !self.is_day_off(day))
+            .ok_or_else(|| Error::CalendarError("semaine entièrement chômée".to_string()))
+    }
+
+    /// Nombre de jours ouvrés dans la semaine contenant `date`.
+    pub fn working_days_in_week(&self, date: NaiveDate) -> i64 {
+        let start = self.start_of_week(date);
+
+        (0..7)
+            .map(|offset| add_days(start, offset))
+            .filter(|&day| !self.is_day_off(day))
+            .count() as i64
+    }
+
+    fn start_of_week(&self, date: NaiveDate) -> NaiveDate {
+        let from_start = date.weekday().days_since(self.week_start) as i32;
+
+        minus_days(date, from_start)
+    }
+
+    fn opening_intervals(&self, date: NaiveDate) -> &[(Duration, Duration)] {
+        &self.opening_hours[date.weekday().num_days_from_monday() as usize]
+    }
+
     pub fn get_next_working_day_with_hours(&self, datetime: NaiveDateTime, hours_added: i32) -> Result<NaiveDateTime> {
-        self.get_working_day_at_hour(datetime, hours_added, add_hours)
+        let mut remaining = Duration::hours(hours_added.into());
+        let mut cursor = datetime;
+        let mut day = datetime.date();
+
+        loop {
+            if !self.is_day_off(day) {
+                for &(start, end) in self.opening_intervals(day) {
+                    let open = day_instant(day, start);
+                    let close = day_instant(day, end);
+
+                    if cursor < close {
+                        let effective_start = cursor.max(open);
+                        let available = close - effective_start;
+
+                        if remaining <= available {
+                            return Ok(effective_start + remaining);
+                        }
+                        remaining -= available;
+                    }
+                }
+            }
+
+            day = add_days(day, 1);
+            cursor = day_instant(day, Duration::zero());
+        }
     }
 
     pub fn get_previous_working_day_with_hours(&self, datetime: NaiveDateTime, hours_added: i32) -> Result<NaiveDateTime> {
-        self.get_working_day_at_hour(datetime, hours_added, minus_hours)
+        let mut remaining = Duration::hours(hours_added.into());
+        let mut cursor = datetime;
+        let mut day = datetime.date();
+
+        loop {
+            if !self.is_day_off(day) {
+                for &(start, end) in self.opening_intervals(day).iter().rev() {
+                    let open = day_instant(day, start);
+                    let close = day_instant(day, end);
+
+                    if cursor > open {
+                        let effective_end = cursor.min(close);
+                        let available = effective_end - open;
+
+                        if remaining <= available {
+                            return Ok(effective_end - remaining);
+                        }
+                        remaining -= available;
+                    }
+                }
+            }
+
+            day = minus_days(day, 1);
+            cursor = day_instant(add_days(day, 1), Duration::zero());
+        }
     }
 
-    fn get_working_day_at_hour(
-        &self,
-        date: NaiveDateTime,
-        nb_hours: i32,
-        datetime_leaper: impl Fn(NaiveDateTime, i32) -> NaiveDateTime,
-    ) -> Result<NaiveDateTime> {
-        let other_date = datetime_leaper(date, nb_hours);
-
-        if self.is_day_off(other_date.date()) {
-            self.get_working_day_at_hour(other_date, 24, datetime_leaper)
-        } else {
-            Ok(other_date)
+}
+
+/// Itérateur infini sur les jours ouvrés, renvoyé par [`Calendar::working_days_from`].
+pub struct WorkingDays<'a> {
+    calendar: &'a Calendar,
+    next: NaiveDate,
+}
+
+impl Iterator for WorkingDays<'_> {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        while self.calendar.is_day_off(self.next) {
+            self.next = add_days(self.next, 1);
         }
+
+        let current = self.next;
+        self.next = add_days(self.next, 1);
+
+        Some(current)
     }
+}
 
+/// Itérateur sur les jours fériés d'un intervalle, renvoyé par
+/// [`Calendar::holidays_between`].
+pub struct HolidaysBetween<'a> {
+    calendar: &'a Calendar,
+    next: NaiveDate,
+    end: NaiveDate,
+}
+
+impl Iterator for HolidaysBetween<'_> {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        while self.next < self.end {
+            let current = self.next;
+            self.next = add_days(self.next, 1);
+
+            if self.calendar.is_holiday(current) {
+                return Some(current);
+            }
+        }
+
+        None
+    }
 }
 
 fn get_easter(year: i32) -> Result<NaiveDate> {
@@ -113,34 +470,6 @@ fn get_easter(year: i32) -> Result<NaiveDate> {
     get_date(year, month, day)
 }
 
-fn build_holidays(year: i32) -> Result<Vec<NaiveDate>> {
-    let easter = get_easter(year)?;
-    let mut holidays: Vec<Vec<NaiveDate>> = vec![];
-
-    //generation de 3 ans pour les cas ou passage d'année (A-1, A, A+1)
-    for y in [year - 1, year, year + 1] {
-        let days = vec![
-            get_date(y, chrono::Month::January.number_from_month(), 1)?,
-            easter + Duration::days(1),  //lundi de Pâques
-            easter + Duration::days(39), //jeudi Ascension
-            easter + Duration::days(50), //Pentecôte
-            get_date(y, chrono::Month::May.number_from_month(), 1)?,
-            get_date(y, chrono::Month::May.number_from_month(), 8)?,
-            get_date(y, chrono::Month::July.number_from_month(), 14)?,
-            get_date(y, chrono::Month::August.number_from_month(), 15)?,
-            get_date(y, chrono::Month::November.number_from_month(), 1)?,
-            get_date(y, chrono::Month::November.number_from_month(), 11)?,
-            get_date(y, chrono::Month::December.number_from_month(), 25)?,
-        ];
-
-        holidays.push(days);
-    }
-
-    let holidays = holidays.into_iter().flatten().collect::<Vec<_>>();
-
-    Ok(holidays)
-}
-
 fn divide(dividende: u32, diviseur: u32) -> Result<(u32, u32)> {
     let result = dividende / diviseur;
     let rest = dividende % diviseur;
@@ -156,15 +485,10 @@ fn minus_days(date: NaiveDate, nb_days: i32) -> NaiveDate {
     date - Duration::days(nb_days.into())
 }
 
-fn add_hours(datetime: NaiveDateTime, nb_hours: i32) -> NaiveDateTime {
-    datetime + Duration::hours(nb_hours.into())
+fn day_instant(date: NaiveDate, offset: Duration) -> NaiveDateTime {
+    NaiveDateTime::new(date, NaiveTime::from_hms_opt(0, 0, 0).unwrap()) + offset
 }
 
-fn minus_hours(datetime: NaiveDateTime, nb_hours: i32) -> NaiveDateTime {
-    datetime - Duration::hours(nb_hours.into())
-}
-
-
 fn get_date(year: i32, month: u32, day: u32) -> Result<NaiveDate> {
     NaiveDate::from_ymd_opt(year, month, day)
         .ok_or(Error::CalendarError(format!("{year}-{month}-{day}")))
@@ -399,6 +723,153 @@ mod tests {
         )
     }
 
+    #[test]
+    fn numero_de_semaine() {
+        let cal = Calendar::new(2018).unwrap();
+        let day = NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 11).unwrap();
+
+        assert_eq!(cal.week_of(day), 28);
+    }
+
+    #[test]
+    fn premier_jour_ouvre_de_la_semaine() {
+        let cal = Calendar::new(2018).unwrap();
+        let day = NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 11).unwrap();
+        let lundi = NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 9).unwrap();
+
+        assert_eq!(cal.first_working_day_of_week(day).unwrap(), lundi);
+    }
+
+    #[test]
+    fn jours_ouvres_dans_la_semaine() {
+        let cal = Calendar::new(2018).unwrap();
+        // semaine du 9 au 15 juillet : samedi 14 férié, dimanche 15 chômé
+        let day = NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 11).unwrap();
+
+        assert_eq!(cal.working_days_in_week(day), 5);
+    }
+
+    #[test]
+    fn heures_ouvrees_saute_la_pause_midi() {
+        let cal = Calendar::builder(2018..=2018)
+            .with_opening_hours(
+                Weekday::Mon,
+                vec![
+                    (
+                        NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                        NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+                    ),
+                    (
+                        NaiveTime::from_hms_opt(13, 0, 0).unwrap(),
+                        NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+                    ),
+                ],
+            )
+            .build()
+            .unwrap();
+        // lundi 9 juillet 2018 à 11h + 2h ouvrées : 1h avant midi, 1h après 13h
+        let date = new_date_time(2018, Month::July, 9, 11, 0, 0);
+        let expected = new_date_time(2018, Month::July, 9, 14, 0, 0);
+
+        assert_eq!(cal.get_next_working_day_with_hours(date, 2).unwrap(), expected);
+    }
+
+    #[test]
+    fn compte_jours_ouvres_entre() {
+        let cal = Calendar::new(2018).unwrap();
+        let from = NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 9).unwrap();
+        let to = NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 16).unwrap();
+
+        // semaine du 9 au 13 inclus, 14 (samedi/férié) et 15 (dimanche) exclus
+        assert_eq!(cal.working_days_between(from, to).unwrap(), 5);
+        assert_eq!(cal.working_days_between(to, from).unwrap(), -5);
+    }
+
+    #[test]
+    fn duree_ouvree_nette() {
+        let cal = Calendar::new(2018).unwrap();
+        let from = new_date_time(2018, Month::July, 13, 12, 0, 0);
+        let to = new_date_time(2018, Month::July, 16, 12, 0, 0);
+
+        // 12h le vendredi + 0 le week-end + 12h le lundi
+        assert_eq!(cal.net_working_duration(from, to).unwrap(), Duration::hours(24));
+    }
+
+    #[test]
+    fn prochains_jours_ouvres() {
+        let cal = Calendar::new(2018).unwrap();
+        let start = NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 13).unwrap();
+        let days: Vec<_> = cal.working_days_from(start).take(3).collect();
+
+        assert_eq!(
+            days,
+            vec![
+                NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 13).unwrap(),
+                NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 16).unwrap(),
+                NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 17).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn jours_feries_dans_intervalle() {
+        let cal = Calendar::new(2018).unwrap();
+        let from = NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2018, Month::September.number_from_month(), 1).unwrap();
+        let holidays: Vec<_> = cal.holidays_between(from, to).collect();
+
+        assert_eq!(
+            holidays,
+            vec![
+                NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 14).unwrap(),
+                NaiveDate::from_ymd_opt(2018, Month::August.number_from_month(), 15).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn preset_alsace_moselle_vendredi_saint() {
+        let cal = Calendar::builder(2017..=2019)
+            .with_preset(Region::AlsaceMoselle)
+            .build()
+            .unwrap();
+        let vendredi_saint =
+            NaiveDate::from_ymd_opt(2018, Month::March.number_from_month(), 30).unwrap();
+        let saint_etienne =
+            NaiveDate::from_ymd_opt(2018, Month::December.number_from_month(), 26).unwrap();
+
+        assert!(cal.is_day_off(vendredi_saint));
+        assert!(cal.is_day_off(saint_etienne));
+    }
+
+    #[test]
+    fn preset_alsace_moselle_inconnu_en_france() {
+        let cal = Calendar::new(2018).unwrap();
+        let saint_etienne =
+            NaiveDate::from_ymd_opt(2018, Month::December.number_from_month(), 26).unwrap();
+
+        assert!(!cal.is_day_off(saint_etienne));
+    }
+
+    #[test]
+    fn builder_jour_ferie_arbitraire() {
+        let pont = NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 13).unwrap();
+        let cal = Calendar::builder(2018..=2018).with_holiday(pont).build().unwrap();
+
+        assert!(cal.is_day_off(pont));
+    }
+
+    #[test]
+    fn builder_samedi_travaille() {
+        let cal = Calendar::builder(2018..=2018)
+            .without_weekend_day(Weekday::Sat)
+            .build()
+            .unwrap();
+        let samedi = NaiveDate::from_ymd_opt(2018, Month::July.number_from_month(), 7).unwrap();
+
+        assert!(!cal.is_day_off(samedi));
+    }
+
     fn new_date_time(year: i32, month: Month, day: u32, hour: u32, minute: u32, seconde: u32) -> NaiveDateTime {
         let date = NaiveDate::from_ymd_opt(year, month.number_from_month(), day).unwrap();
         let time = NaiveTime::from_hms_opt(hour, minute, seconde).unwrap();        
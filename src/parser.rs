@@ -1,6 +1,7 @@
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime, Weekday};
 
-use crate::{Result, Error};
+use crate::calendar::Calendar;
+use crate::{Error, Result};
 
 /// value: yyyy-mm-dd
 pub fn parse_from_iso_date(value: &str) -> Result<NaiveDate> {
@@ -12,4 +13,263 @@ pub fn parse_from_iso_date(value: &str) -> Result<NaiveDate> {
 pub fn parse_from_iso_date_time(value: &str) -> Result<NaiveDateTime> {
    NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
       .map_err(|e| Error::ParamsError(e.to_string()))
+}
+
+enum Unit {
+   Day,
+   Week,
+   Month,
+}
+
+/// value: `[+][-]<digits?><unit?>`, unité ∈ {`d`, `w`, `m`}, par défaut `d`.
+///
+/// Applique un décalage de calendrier à `base` puis recale le résultat sur un
+/// jour ouvré au travers de `calendar` : le décalage porte sur des jours (ou
+/// semaines = 7 jours, ou mois) calendaires, pas sur un compte de jours ouvrés,
+/// et emprunte la sémantique de [`Calendar::get_next_working_day`] /
+/// [`Calendar::get_previous_working_day`] (décale de N jours puis se recale sur
+/// le jour ouvré suivant, ou précédent avec `-`). Le `+` de tête rend le décalage « strict » : le compte
+/// est exact et ne se résorbe pas lorsque `base` est déjà un jour ouvré. Le `-`
+/// inverse le sens. Les mois décalent les mois calendaires puis recalent sur un
+/// jour ouvré.
+pub fn parse_business_offset(value: &str, base: NaiveDate, calendar: &Calendar) -> Result<NaiveDate> {
+   let mut rest = value;
+
+   let strict = rest.starts_with('+');
+   if strict {
+      rest = &rest[1..];
+   }
+
+   let backward = rest.starts_with('-');
+   if backward {
+      rest = &rest[1..];
+   }
+
+   let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+   let rest = &rest[digits.len()..];
+
+   let count: i64 = if digits.is_empty() {
+      1
+   } else {
+      digits
+         .parse()
+         .map_err(|_| Error::ParamsError(format!("offset invalide: {value}")))?
+   };
+
+   let unit = match rest {
+      "" | "d" => Unit::Day,
+      "w" => Unit::Week,
+      "m" => Unit::Month,
+      _ => return Err(Error::ParamsError(format!("unité invalide: {value}"))),
+   };
+
+   match unit {
+      Unit::Day | Unit::Week => {
+         let multiplier = if matches!(unit, Unit::Week) { 7 } else { 1 };
+         let days = count
+            .checked_mul(multiplier)
+            .ok_or_else(|| Error::ParamsError(format!("dépassement: {value}")))?;
+         let mut days: i32 = days
+            .try_into()
+            .map_err(|_| Error::ParamsError(format!("dépassement: {value}")))?;
+
+         if !strict && days > 0 && !calendar.is_day_off(base) {
+            days -= 1;
+         }
+
+         if backward {
+            calendar.get_previous_working_day(base, days)
+         } else {
+            calendar.get_next_working_day(base, days)
+         }
+      }
+      Unit::Month => {
+         let months: u32 = count
+            .try_into()
+            .map_err(|_| Error::ParamsError(format!("dépassement: {value}")))?;
+         let months = Months::new(months);
+
+         let shifted = if backward {
+            base.checked_sub_months(months)
+         } else {
+            base.checked_add_months(months)
+         }
+         .ok_or_else(|| Error::ParamsError(format!("dépassement: {value}")))?;
+
+         if backward {
+            calendar.get_previous_working_day(shifted, 0)
+         } else {
+            calendar.get_next_working_day(shifted, 0)
+         }
+      }
+   }
+}
+
+/// Résout une expression de week-end relative à `reference` et renvoie le
+/// couple `(samedi, dimanche)`. La semaine débute le lundi : « this weekend »
+/// est le samedi/dimanche de la semaine contenant `reference`, « last » et
+/// « next » décalent de sept jours.
+pub fn resolve_anchor(phrase: &str, reference: NaiveDate) -> Result<(NaiveDate, NaiveDate)> {
+   let offset_weeks = match phrase.trim().to_lowercase().as_str() {
+      "this weekend" => 0,
+      "last weekend" => -1,
+      "next weekend" => 1,
+      _ => return Err(Error::ParamsError(format!("expression inconnue: {phrase}"))),
+   };
+
+   let from_monday = reference.weekday().num_days_from_monday() as i64;
+   let saturday = reference + Duration::days(5 - from_monday + offset_weeks * 7);
+
+   Ok((saturday, saturday + Duration::days(1)))
+}
+
+/// Résout une expression du type « next monday » / « last friday » relative à
+/// `reference` et renvoie la prochaine (ou précédente) occurrence stricte du
+/// jour nommé.
+pub fn resolve_weekday(phrase: &str, reference: NaiveDate) -> Result<NaiveDate> {
+   let phrase = phrase.trim().to_lowercase();
+   let (direction, name) = phrase
+      .split_once(' ')
+      .ok_or_else(|| Error::ParamsError(format!("expression inconnue: {phrase}")))?;
+
+   let target = parse_weekday(name)?;
+   let reference_day = reference.weekday().num_days_from_monday();
+   let target_day = target.num_days_from_monday();
+
+   match direction {
+      "next" => {
+         let ahead = (target_day + 7 - reference_day) % 7;
+         let ahead = if ahead == 0 { 7 } else { ahead };
+         Ok(reference + Duration::days(ahead as i64))
+      }
+      "last" | "previous" => {
+         let back = (reference_day + 7 - target_day) % 7;
+         let back = if back == 0 { 7 } else { back };
+         Ok(reference - Duration::days(back as i64))
+      }
+      _ => Err(Error::ParamsError(format!("expression inconnue: {phrase}"))),
+   }
+}
+
+fn parse_weekday(name: &str) -> Result<Weekday> {
+   match name {
+      "monday" => Ok(Weekday::Mon),
+      "tuesday" => Ok(Weekday::Tue),
+      "wednesday" => Ok(Weekday::Wed),
+      "thursday" => Ok(Weekday::Thu),
+      "friday" => Ok(Weekday::Fri),
+      "saturday" => Ok(Weekday::Sat),
+      "sunday" => Ok(Weekday::Sun),
+      _ => Err(Error::ParamsError(format!("jour inconnu: {name}"))),
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn offset_strict_jours() {
+      let cal = Calendar::new(2018).unwrap();
+      let base = NaiveDate::from_ymd_opt(2018, 7, 9).unwrap();
+
+      assert_eq!(
+         parse_business_offset("+5", base, &cal).unwrap(),
+         NaiveDate::from_ymd_opt(2018, 7, 16).unwrap()
+      );
+   }
+
+   #[test]
+   fn offset_non_strict_se_resorbe() {
+      let cal = Calendar::new(2018).unwrap();
+      let base = NaiveDate::from_ymd_opt(2018, 7, 9).unwrap();
+
+      assert_eq!(parse_business_offset("1", base, &cal).unwrap(), base);
+   }
+
+   #[test]
+   fn offset_negatif() {
+      let cal = Calendar::new(2018).unwrap();
+      let base = NaiveDate::from_ymd_opt(2018, 7, 13).unwrap();
+
+      assert_eq!(
+         parse_business_offset("+-2", base, &cal).unwrap(),
+         NaiveDate::from_ymd_opt(2018, 7, 11).unwrap()
+      );
+   }
+
+   #[test]
+   fn offset_semaine() {
+      let cal = Calendar::new(2018).unwrap();
+      let base = NaiveDate::from_ymd_opt(2018, 7, 9).unwrap();
+
+      assert_eq!(
+         parse_business_offset("+1w", base, &cal).unwrap(),
+         NaiveDate::from_ymd_opt(2018, 7, 16).unwrap()
+      );
+   }
+
+   #[test]
+   fn offset_garbage() {
+      let cal = Calendar::new(2018).unwrap();
+      let base = NaiveDate::from_ymd_opt(2018, 7, 9).unwrap();
+
+      assert!(parse_business_offset("5x", base, &cal).is_err());
+   }
+
+   #[test]
+   fn anchor_this_weekend() {
+      // mercredi 11 juillet 2018
+      let reference = NaiveDate::from_ymd_opt(2018, 7, 11).unwrap();
+
+      assert_eq!(
+         resolve_anchor("this weekend", reference).unwrap(),
+         (
+            NaiveDate::from_ymd_opt(2018, 7, 14).unwrap(),
+            NaiveDate::from_ymd_opt(2018, 7, 15).unwrap()
+         )
+      );
+   }
+
+   #[test]
+   fn anchor_next_weekend() {
+      let reference = NaiveDate::from_ymd_opt(2018, 7, 11).unwrap();
+
+      assert_eq!(
+         resolve_anchor("next weekend", reference).unwrap(),
+         (
+            NaiveDate::from_ymd_opt(2018, 7, 21).unwrap(),
+            NaiveDate::from_ymd_opt(2018, 7, 22).unwrap()
+         )
+      );
+   }
+
+   #[test]
+   fn weekday_next_monday() {
+      // mercredi 11 juillet 2018
+      let reference = NaiveDate::from_ymd_opt(2018, 7, 11).unwrap();
+
+      assert_eq!(
+         resolve_weekday("next monday", reference).unwrap(),
+         NaiveDate::from_ymd_opt(2018, 7, 16).unwrap()
+      );
+   }
+
+   #[test]
+   fn weekday_last_friday() {
+      let reference = NaiveDate::from_ymd_opt(2018, 7, 11).unwrap();
+
+      assert_eq!(
+         resolve_weekday("last friday", reference).unwrap(),
+         NaiveDate::from_ymd_opt(2018, 7, 6).unwrap()
+      );
+   }
+
+   #[test]
+   fn phrase_inconnue() {
+      let reference = NaiveDate::from_ymd_opt(2018, 7, 11).unwrap();
+
+      assert!(resolve_anchor("someday", reference).is_err());
+      assert!(resolve_weekday("next funday", reference).is_err());
+   }
 }
\ No newline at end of file